@@ -0,0 +1,229 @@
+// LICENSE: CC0-1.0
+
+use crate::{TextRange, Utf16Position, Utf8Index, Utf8Position};
+
+/// Per-line table of non-ASCII characters, recording each one's UTF-8 column
+/// offset on the line along with its UTF-16 and UTF-8 lengths, as
+/// `(offset_in_line, utf16_len, utf8_len)`.
+type WideChars = Vec<(u32, u32, u32)>;
+
+/// Precomputed table for converting between byte offsets and row/column
+/// positions against a fixed piece of source text.
+///
+/// Built once from `&str` in a single scan; offset-to-position lookups are
+/// `O(log lines)`, and UTF-8/UTF-16 column translation walks the row's
+/// wide-character table.
+///
+/// ```
+/// use text_position_rs::{LineIndex, Utf8Index, Utf8Position};
+///
+/// let index = LineIndex::new("ab\ncd");
+/// assert_eq!(index.offset_to_position(Utf8Index::new(4)), Utf8Position::new(1, 1));
+/// assert_eq!(index.position_to_offset(Utf8Position::new(1, 1)), Utf8Index::new(4));
+/// ```
+pub struct LineIndex {
+    /// Byte offset of the start of each line. Always starts with `Utf8Index::new(0)`.
+    ///
+    /// An offset exactly at a line's terminating `\n` maps to end-of-line on
+    /// that row; a synthetic final row starts right after the text's last `\n`.
+    newlines: Vec<Utf8Index>,
+
+    /// `wide_chars[row]` lists the non-ASCII characters on that row, in order.
+    wide_chars: Vec<WideChars>,
+}
+
+impl LineIndex {
+    /// Scan `text` once, recording line start offsets and wide-character tables.
+    pub fn new(text: &str) -> Self {
+        let mut newlines = vec![Utf8Index::new(0)];
+        let mut wide_chars: Vec<WideChars> = vec![Vec::new()];
+        let mut line_start = 0;
+
+        for (offset, c) in text.char_indices() {
+            let offset = offset as u32;
+            let utf8_len = c.len_utf8() as u32;
+            let utf16_len = c.len_utf16() as u32;
+
+            if utf8_len > 1 {
+                wide_chars
+                    .last_mut()
+                    .unwrap()
+                    .push((offset - line_start, utf16_len, utf8_len));
+            }
+
+            if c == '\n' {
+                line_start = offset + utf8_len;
+                newlines.push(Utf8Index::new(line_start));
+                wide_chars.push(Vec::new());
+            }
+        }
+
+        Self {
+            newlines,
+            wide_chars,
+        }
+    }
+
+    fn row_of(&self, offset: Utf8Index) -> usize {
+        match self.newlines.binary_search(&offset) {
+            Ok(row) => row,
+            Err(row) => row - 1,
+        }
+    }
+
+    /// Convert a byte offset into a row/column position.
+    pub fn offset_to_position(&self, offset: Utf8Index) -> Utf8Position {
+        let row = self.row_of(offset);
+        let line_start = self.newlines[row];
+        let column = u32::from(offset) - u32::from(line_start);
+        Utf8Position::new(row as u32, column)
+    }
+
+    /// Convert a row/column position back into a byte offset.
+    ///
+    /// Inverse of [`offset_to_position`](Self::offset_to_position).
+    pub fn position_to_offset(&self, position: Utf8Position) -> Utf8Index {
+        let line_start = self.newlines[position.row as usize];
+        Utf8Index::new(u32::from(line_start) + position.column)
+    }
+
+    /// Translate a UTF-8 row/column position into the equivalent UTF-16 position.
+    ///
+    /// Walks the row's wide-character table, counting each non-BMP character as 2 UTF-16 units.
+    pub fn to_utf16(&self, position: Utf8Position) -> Utf16Position {
+        let mut utf8_pos = 0;
+        let mut utf16_pos = 0;
+
+        for &(char_offset, utf16_len, utf8_len) in &self.wide_chars[position.row as usize] {
+            if char_offset >= position.column {
+                break;
+            }
+            utf16_pos += (char_offset - utf8_pos) + utf16_len;
+            utf8_pos = char_offset + utf8_len;
+        }
+
+        let column = utf16_pos + (position.column - utf8_pos);
+        Utf16Position::new(position.row, column)
+    }
+
+    /// Translate a UTF-16 row/column position into the equivalent UTF-8 position.
+    ///
+    /// Inverse of [`to_utf16`](Self::to_utf16).
+    pub fn to_utf8(&self, position: Utf16Position) -> Utf8Position {
+        let mut utf8_pos = 0;
+        let mut utf16_pos = 0;
+
+        for &(char_offset, utf16_len, utf8_len) in &self.wide_chars[position.row as usize] {
+            let ascii_len = char_offset - utf8_pos;
+            if utf16_pos + ascii_len >= position.column {
+                break;
+            }
+            utf8_pos += ascii_len;
+            utf16_pos += ascii_len;
+
+            if utf16_pos + utf16_len > position.column {
+                break;
+            }
+            utf8_pos += utf8_len;
+            utf16_pos += utf16_len;
+        }
+
+        let column = utf8_pos + (position.column - utf16_pos);
+        Utf8Position::new(position.row, column)
+    }
+
+    /// Convert a byte-offset range into the equivalent UTF-16 row/column range.
+    pub fn range_to_utf16(&self, range: TextRange<Utf8Index>) -> TextRange<Utf16Position> {
+        let start = self.to_utf16(self.offset_to_position(range.start()));
+        let end = self.to_utf16(self.offset_to_position(range.end()));
+        TextRange::from(start..end)
+    }
+
+    /// Convert a UTF-16 row/column range back into a byte-offset range.
+    ///
+    /// Inverse of [`range_to_utf16`](Self::range_to_utf16).
+    pub fn range_to_offset(&self, range: TextRange<Utf16Position>) -> TextRange<Utf8Index> {
+        let start = self.position_to_offset(self.to_utf8(range.start()));
+        let end = self.position_to_offset(self.to_utf8(range.end()));
+        TextRange::from(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineIndex;
+    use crate::{TextRange, Utf16Position, Utf8Index, Utf8Position};
+
+    #[test]
+    fn test_offset_to_position_ascii() {
+        let index = LineIndex::new("ab\ncd");
+        assert_eq!(
+            index.offset_to_position(Utf8Index::new(0)),
+            Utf8Position::new(0, 0)
+        );
+        assert_eq!(
+            index.offset_to_position(Utf8Index::new(2)),
+            Utf8Position::new(0, 2)
+        );
+        assert_eq!(
+            index.offset_to_position(Utf8Index::new(3)),
+            Utf8Position::new(1, 0)
+        );
+        assert_eq!(
+            index.offset_to_position(Utf8Index::new(5)),
+            Utf8Position::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_offset_to_position_trailing_newline() {
+        let index = LineIndex::new("ab\n");
+        assert_eq!(
+            index.offset_to_position(Utf8Index::new(3)),
+            Utf8Position::new(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_position_to_offset_roundtrip() {
+        let index = LineIndex::new("ab\ncd");
+        for offset in [0, 1, 2, 3, 4, 5] {
+            let offset = Utf8Index::new(offset);
+            let position = index.offset_to_position(offset);
+            assert_eq!(index.position_to_offset(position), offset);
+        }
+    }
+
+    #[test]
+    fn test_to_utf16_with_wide_char() {
+        let index = LineIndex::new("a🐧b");
+        assert_eq!(
+            index.to_utf16(Utf8Position::new(0, 0)),
+            Utf16Position::new(0, 0)
+        );
+        assert_eq!(
+            index.to_utf16(Utf8Position::new(0, 1)),
+            Utf16Position::new(0, 1)
+        );
+        assert_eq!(
+            index.to_utf16(Utf8Position::new(0, 5)),
+            Utf16Position::new(0, 3)
+        );
+        assert_eq!(
+            index.to_utf8(Utf16Position::new(0, 3)),
+            Utf8Position::new(0, 5)
+        );
+    }
+
+    #[test]
+    fn test_range_to_utf16_roundtrip() {
+        let index = LineIndex::new("a🐧b");
+        let range = TextRange::from(Utf8Index::new(1)..Utf8Index::new(6));
+        let utf16_range = index.range_to_utf16(range);
+        assert_eq!(
+            utf16_range,
+            TextRange::from(Utf16Position::new(0, 1)..Utf16Position::new(0, 4))
+        );
+        assert_eq!(index.range_to_offset(utf16_range), range);
+    }
+}
@@ -3,7 +3,7 @@
 use crate::{position::TextPosition, CompositePosition, Utf16Position, Utf8Index, Utf8Position};
 use std::{
     fmt::{self, Debug, Display, Formatter},
-    ops::{Add, Range},
+    ops::{Add, AddAssign, Index, IndexMut, Range, RangeInclusive, Sub, SubAssign},
 };
 
 // DESIGN: Prefer (index, len) over (start, end)
@@ -71,7 +71,7 @@ impl<P: TextPosition> TextRange<P> {
 
     /// Empty range pointing to the start position.
     pub fn to_start(self) -> Self {
-        Self::empty(self.end())
+        Self::empty(self.start())
     }
 
     /// Empty range pointing to the end position.
@@ -95,6 +95,19 @@ impl<P: TextPosition> TextRange<P> {
         self.clone().start() <= other.clone().start() && other.end() <= self.end()
     }
 
+    /// Clearer alias for [`covers`](Self::covers).
+    pub fn contains_range(self, other: Self) -> bool {
+        self.covers(other)
+    }
+
+    /// Whether the two ranges overlap, i.e. [`intersect`](Self::intersect) is `Some`.
+    ///
+    /// Cheaper than calling `intersect` and checking for `Some` when the
+    /// overlapping range itself isn't needed.
+    pub fn overlaps(self, other: Self) -> bool {
+        self.start().max(other.start()) <= self.end().min(other.end())
+    }
+
     /// Whether the range is empty.
     ///
     /// ```
@@ -136,7 +149,35 @@ impl<P: TextPosition> TextRange<P> {
 
     /// Make a range that is covered by two ranges.
     ///
-    /// Return an empty range at `self.start()` if two are disjoint.
+    /// Return `None` if the two ranges are disjoint.
+    ///
+    /// c.f. `text-size`'s `TextRange::intersect`.
+    ///
+    /// ```
+    /// use text_position_rs::{TextRange, Utf8Index};
+    ///
+    /// let first_range = TextRange::from(Utf8Index::new(2)..Utf8Index::new(6));
+    /// let second_range = TextRange::from(Utf8Index::new(4)..Utf8Index::new(8));
+    /// let met_range = TextRange::from(Utf8Index::new(4)..Utf8Index::new(6));
+    /// assert_eq!(first_range.intersect(second_range), Some(met_range));
+    ///
+    /// // Disjoint case.
+    /// let third_range = TextRange::from(Utf8Index::new(9)..Utf8Index::new(10));
+    /// assert_eq!(first_range.intersect(third_range), None);
+    /// ```
+    pub fn intersect(self, other: Self) -> Option<Self> {
+        let start = self.start().max(other.start());
+        let end = self.end().min(other.end());
+        if start > end {
+            None
+        } else {
+            Some(Self::from(start..end))
+        }
+    }
+
+    /// Make a range that is covered by two ranges.
+    ///
+    /// Return an empty range at `max(self.start(), other.start())` if the two are disjoint.
     ///
     /// ```
     /// use text_position_rs::{TextRange, Utf8Index};
@@ -151,13 +192,21 @@ impl<P: TextPosition> TextRange<P> {
     ///
     /// // Disjoint case.
     /// let third_range = TextRange::from(Utf8Index::new(9)..Utf8Index::new(10));
-    /// assert_eq!(first_range.meet(third_range), first_range.to_start());
+    /// assert_eq!(first_range.meet(third_range), TextRange::empty(Utf8Index::new(9)));
     /// ```
     pub fn meet(self, other: Self) -> Self {
-        // QUESTION: More efficient way? Should return None if disjoint? 
-        let end = self.clone().end().min(other.clone().end());
-        let start = self.start().max(other.start());
-        Self::from(start..end)
+        self.intersect(other)
+            .unwrap_or_else(|| Self::empty(self.start().max(other.start())))
+    }
+
+    /// Shift the range by `offset`, returning `None` on overflow instead of panicking.
+    pub fn checked_add(self, offset: P) -> Option<Self> {
+        Some(Self::at(self.index.checked_add(offset)?, self.len))
+    }
+
+    /// Shift the range by `offset`, returning `None` on underflow instead of panicking.
+    pub fn checked_sub(self, offset: P) -> Option<Self> {
+        Some(Self::at(self.index.checked_sub(offset)?, self.len))
     }
 }
 
@@ -170,23 +219,70 @@ impl<P: TextPosition + Default> Default for TextRange<P> {
 impl<P: TextPosition> From<Range<P>> for TextRange<P> {
     fn from(range: Range<P>) -> Self {
         let Range { start, end } = range;
-        Self {
-            index: start.clone(),
-            len: end.saturating_sub(start),
-        }
+        Self::at(start.clone(), end.saturating_sub(start))
     }
 }
 
 impl<P: TextPosition + Add<Output = P>> From<TextRange<P>> for Range<P> {
     fn from(range: TextRange<P>) -> Self {
-        let TextRange { index, len } = range;
         Range {
-            start: index.clone(),
-            end: index + len,
+            start: range.start(),
+            end: range.end(),
         }
     }
 }
 
+impl<P: TextPosition> From<RangeInclusive<P>> for TextRange<P> {
+    fn from(range: RangeInclusive<P>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::at(start, end.saturating_sub(start) + P::ONE)
+    }
+}
+
+// TODO(chunk0-4): this request also asked for `impl std::ops::RangeBounds<P>
+// for TextRange<P>` directly, which is NOT done here and this item should not
+// be treated as fully closed until that's resolved one way or the other.
+//
+// `RangeBounds::end_bound` must return `&P`, but `index` and `len` are the
+// only (and publicly mutable) fields, so there is nothing long-lived to
+// borrow a computed `index + len` from without caching a derived `end`
+// field — and any such cache could be silently desynced by a direct write
+// to `index` or `len` through the public API (see the reverted attempt in
+// a173901/f9472bd). Before closing this request, either:
+//   - confirm `Range::from(range)` (which already implements `RangeBounds`
+//     via std) is an accepted workaround and drop the `RangeBounds` ask, or
+//   - make `index`/`len` private with accessors so a cached `end` can't
+//     desync, and implement `RangeBounds` on top of that.
+
+/// Shift the range, leaving `len` unchanged. c.f. `text-size`'s `Add`/`Sub` on `TextRange`.
+impl<P: TextPosition + Add<Output = P>> Add<P> for TextRange<P> {
+    type Output = Self;
+
+    fn add(self, offset: P) -> Self {
+        Self::at(self.index + offset, self.len)
+    }
+}
+
+impl<P: TextPosition + Sub<Output = P>> Sub<P> for TextRange<P> {
+    type Output = Self;
+
+    fn sub(self, offset: P) -> Self {
+        Self::at(self.index - offset, self.len)
+    }
+}
+
+impl<P: TextPosition + Add<Output = P>> AddAssign<P> for TextRange<P> {
+    fn add_assign(&mut self, offset: P) {
+        *self = *self + offset;
+    }
+}
+
+impl<P: TextPosition + Sub<Output = P>> SubAssign<P> for TextRange<P> {
+    fn sub_assign(&mut self, offset: P) {
+        *self = *self - offset;
+    }
+}
+
 impl Debug for TextRange<Utf8Index> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
@@ -205,6 +301,59 @@ impl Debug for TextRange<Utf8Position> {
     }
 }
 
+/// Panics naming the offending byte offset, distinguishing an out-of-bounds
+/// offset from one that falls inside a multi-byte character, matching
+/// `str`'s own two distinct slice-panic conditions.
+fn assert_char_boundary(s: &str, offset: usize) {
+    if offset > s.len() {
+        panic!(
+            "byte index {} is out of bounds of `{}`, which has length {}",
+            offset,
+            s,
+            s.len()
+        );
+    }
+    if !s.is_char_boundary(offset) {
+        panic!("byte index {} is not a char boundary in `{}`", offset, s);
+    }
+}
+
+impl Index<TextRange<Utf8Index>> for str {
+    type Output = str;
+
+    fn index(&self, range: TextRange<Utf8Index>) -> &str {
+        let start = u32::from(range.start()) as usize;
+        let end = u32::from(range.end()) as usize;
+        assert_char_boundary(self, start);
+        assert_char_boundary(self, end);
+        &self[start..end]
+    }
+}
+
+impl IndexMut<TextRange<Utf8Index>> for str {
+    fn index_mut(&mut self, range: TextRange<Utf8Index>) -> &mut str {
+        let start = u32::from(range.start()) as usize;
+        let end = u32::from(range.end()) as usize;
+        assert_char_boundary(self, start);
+        assert_char_boundary(self, end);
+        &mut self[start..end]
+    }
+}
+
+impl Index<TextRange<Utf8Index>> for String {
+    type Output = str;
+
+    fn index(&self, range: TextRange<Utf8Index>) -> &str {
+        &self.as_str()[range]
+    }
+}
+
+impl IndexMut<TextRange<Utf8Index>> for String {
+    fn index_mut(&mut self, range: TextRange<Utf8Index>) -> &mut str {
+        &mut self.as_mut_str()[range]
+    }
+}
+
 /// <https://www.gnu.org/prep/standards/html_node/Errors.html>
 fn fmt_gnu(
     f: &mut Formatter,
@@ -273,6 +422,116 @@ mod tests {
         assert!(range.contains_inclusive(Utf8Position::from(s)));
     }
 
+    #[test]
+    fn test_index_str() {
+        use crate::Utf8Index;
+
+        let s = "Hello, world";
+        let range = TextRange::from(Utf8Index::new(7)..Utf8Index::new(12));
+        assert_eq!(&s[range], "world");
+    }
+
+    #[test]
+    #[should_panic(expected = "char boundary")]
+    fn test_index_str_non_char_boundary() {
+        use crate::Utf8Index;
+
+        let s = "🐧";
+        let range = TextRange::from(Utf8Index::new(0)..Utf8Index::new(1));
+        let _ = &s[range];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_str_out_of_bounds() {
+        use crate::Utf8Index;
+
+        let s = "abc";
+        let range = TextRange::from(Utf8Index::new(0)..Utf8Index::new(10));
+        let _ = &s[range];
+    }
+
+    #[test]
+    fn test_index_string() {
+        use crate::Utf8Index;
+
+        let mut s = String::from("Hello, world");
+        let range = TextRange::from(Utf8Index::new(7)..Utf8Index::new(12));
+        assert_eq!(&s[range], "world");
+
+        s[range].make_ascii_uppercase();
+        assert_eq!(s, "Hello, WORLD");
+    }
+
+    #[test]
+    fn test_to_start_and_to_end() {
+        use crate::Utf8Index;
+
+        let range = TextRange::from(Utf8Index::new(2)..Utf8Index::new(6));
+        assert_eq!(range.to_start(), TextRange::empty(Utf8Index::new(2)));
+        assert_eq!(range.to_end(), TextRange::empty(Utf8Index::new(6)));
+    }
+
+    #[test]
+    fn test_intersect_disjoint() {
+        use crate::Utf8Index;
+
+        let first_range = TextRange::from(Utf8Index::new(2)..Utf8Index::new(6));
+        let third_range = TextRange::from(Utf8Index::new(9)..Utf8Index::new(10));
+        assert_eq!(first_range.intersect(third_range), None);
+        assert!(!first_range.overlaps(third_range));
+    }
+
+    #[test]
+    fn test_overlaps_and_contains_range() {
+        use crate::Utf8Index;
+
+        let outer = TextRange::from(Utf8Index::new(2)..Utf8Index::new(8));
+        let inner = TextRange::from(Utf8Index::new(4)..Utf8Index::new(6));
+        assert!(outer.overlaps(inner));
+        assert!(outer.contains_range(inner));
+        assert!(!inner.contains_range(outer));
+    }
+
+    #[test]
+    fn test_add_sub_shift() {
+        use crate::Utf8Index;
+
+        let range = TextRange::from(Utf8Index::new(2)..Utf8Index::new(6));
+        let shifted = range + Utf8Index::new(3);
+        assert_eq!(
+            shifted,
+            TextRange::from(Utf8Index::new(5)..Utf8Index::new(9))
+        );
+        assert_eq!(shifted - Utf8Index::new(3), range);
+
+        let mut range = range;
+        range += Utf8Index::new(3);
+        assert_eq!(range, shifted);
+        range -= Utf8Index::new(3);
+        assert_eq!(range, TextRange::from(Utf8Index::new(2)..Utf8Index::new(6)));
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        use crate::Utf8Index;
+
+        let range = TextRange::from(Utf8Index::new(2)..Utf8Index::new(6));
+        assert_eq!(
+            range.checked_add(Utf8Index::new(3)),
+            Some(TextRange::from(Utf8Index::new(5)..Utf8Index::new(9)))
+        );
+        assert_eq!(range.checked_sub(Utf8Index::new(3)), None);
+    }
+
+    #[test]
+    fn test_from_range_inclusive() {
+        use crate::Utf8Index;
+
+        let range = TextRange::from(Utf8Index::new(2)..=Utf8Index::new(4));
+        assert_eq!(range, TextRange::from(Utf8Index::new(2)..Utf8Index::new(5)));
+    }
+
     #[test]
     fn test_display_zero() {
         assert_eq!(format!("{}", TextRange::<Utf8Position>::ZERO), "1.1-1.1");